@@ -0,0 +1,86 @@
+//! A tiny terminal client to play Othello against the bot.
+
+use std::io::{self, Write};
+
+use othebot::engine::{self, Difficulty};
+use othebot::{algebric2xy, Disc, Game};
+
+fn parse_difficulty(arg: &str) -> Option<Difficulty> {
+    match arg {
+        "easy" => Some(Difficulty::Easy),
+        "medium" => Some(Difficulty::Medium),
+        "hard" => Some(Difficulty::Hard),
+        "expert" => Some(Difficulty::Expert),
+        _ => None,
+    }
+}
+
+fn main() {
+    let mut difficulty = Difficulty::Medium;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--difficulty" | "-d" => {
+                let Some(value) = args.next() else {
+                    eprintln!("--difficulty expects a value (easy, medium, hard or expert)");
+                    return;
+                };
+                let Some(parsed) = parse_difficulty(&value) else {
+                    eprintln!("unknown difficulty `{value}`, expected easy, medium, hard or expert");
+                    return;
+                };
+                difficulty = parsed;
+            }
+            _ => {
+                eprintln!("unknown argument `{arg}`");
+                return;
+            }
+        }
+    }
+
+    // The human plays Black (Othello's first player), the bot plays White.
+    let mut game = Game::new("Othebot", "You");
+    // Reused across the whole game so transpositions found on earlier moves
+    // still pay off on later ones.
+    let mut tt = engine::TranspositionTable::with_capacity(1 << 16);
+
+    loop {
+        game.legal_moves();
+        game.render().unwrap();
+
+        if game.is_over() {
+            match game.winner() {
+                Some(winner) => println!("Game over, {winner} wins!"),
+                None => println!("Game over, it's a tie!"),
+            }
+            break;
+        }
+
+        if game.turn() == Disc::White {
+            let Some((row, col)) = engine::best_move(&game, difficulty.depth(), &mut tt) else {
+                unreachable!("is_over() would have caught this");
+            };
+            println!("Othebot plays {}{}", (b'a' + col) as char, row + 1);
+            game.make_turn((row, col)).unwrap();
+            continue;
+        }
+
+        print!("Your move (e.g. d3): ");
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        if io::stdin().read_line(&mut input).unwrap() == 0 {
+            break;
+        }
+
+        let Ok((col, row)) = algebric2xy(input.trim()) else {
+            println!("invalid coordinates, try again");
+            continue;
+        };
+
+        if let Err(err) = game.make_turn((row, col)) {
+            println!("{err}");
+        }
+    }
+}
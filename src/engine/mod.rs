@@ -0,0 +1,317 @@
+//! A small Othello-playing bot: negamax search with alpha-beta pruning over
+//! the [`Board`] bitboard primitives. [`best_move`] drives a heuristic
+//! midgame search that hands off to an exact disc-differential search once
+//! few empty squares remain, sped up by a Zobrist-keyed transposition table
+//! (see the [`table`] module).
+
+use crate::{iter_bits, shift_dir, Board, Disc, Game, DIRECTIONS};
+
+mod table;
+
+pub use table::TranspositionTable;
+
+use table::Bound;
+
+/// How hard the bot should look for a move, mapped to a search depth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+    Expert,
+}
+
+impl Difficulty {
+    /// The midgame search depth associated with this difficulty.
+    #[must_use]
+    pub fn depth(self) -> u8 {
+        match self {
+            Difficulty::Easy => 2,
+            Difficulty::Medium => 4,
+            Difficulty::Hard => 6,
+            Difficulty::Expert => 9,
+        }
+    }
+}
+
+/// Once this few empty squares remain, the search switches from the
+/// heuristic evaluation to an exact disc-differential search all the way to
+/// the end of the game.
+const ENDGAME_EXACT_EMPTIES: u32 = 10;
+
+/// Classic Othello disc-square weights: corners are highly valuable, the
+/// X-squares and C-squares next to an empty corner are dangerous to occupy
+/// early since they give the opponent access to that corner.
+#[rustfmt::skip]
+const SQUARE_WEIGHTS: [i32; 64] = [
+    120, -20,  20,   5,   5,  20, -20, 120,
+    -20, -40,  -5,  -5,  -5,  -5, -40, -20,
+     20,  -5,  15,   3,   3,  15,  -5,  20,
+      5,  -5,   3,   3,   3,   3,  -5,   5,
+      5,  -5,   3,   3,   3,   3,  -5,   5,
+     20,  -5,  15,   3,   3,  15,  -5,  20,
+    -20, -40,  -5,  -5,  -5,  -5, -40, -20,
+    120, -20,  20,   5,   5,  20, -20, 120,
+];
+
+const MOBILITY_WEIGHT: i32 = 10;
+const FRONTIER_WEIGHT: i32 = 3;
+
+fn square_score(discs: u64) -> i32 {
+    iter_bits(discs).map(|pos| SQUARE_WEIGHTS[pos as usize]).sum()
+}
+
+/// Bitboard of every disc that is adjacent to at least one empty square,
+/// i.e. exposed to being flipped.
+fn frontier(occupied: u64) -> u64 {
+    let empty = !occupied;
+    let mut exposed = 0;
+    for dir in &DIRECTIONS {
+        exposed |= shift_dir(empty, dir);
+    }
+    exposed & occupied
+}
+
+/// Leaf evaluation for the midgame search: weighted disc-square tables,
+/// mobility, and frontier-disc count, from `player`'s point of view.
+fn evaluate(board: &Board, player: Disc) -> i32 {
+    let opponent = !player;
+    let (mine, theirs) = board.bitboards_for(player);
+
+    let square_diff = square_score(mine) - square_score(theirs);
+
+    let mobility_diff = board.legal_moves(player).count_ones() as i32
+        - board.legal_moves(opponent).count_ones() as i32;
+
+    let frontier = frontier(board.occupied());
+    let frontier_diff =
+        (frontier & mine).count_ones() as i32 - (frontier & theirs).count_ones() as i32;
+
+    square_diff + MOBILITY_WEIGHT * mobility_diff - FRONTIER_WEIGHT * frontier_diff
+}
+
+/// The exact disc differential, from `player`'s point of view. Only
+/// meaningful once the game (or search) has reached a terminal position.
+fn disc_diff(board: &Board, player: Disc) -> i32 {
+    let (mine, theirs) = board.bitboards_for(player);
+    mine.count_ones() as i32 - theirs.count_ones() as i32
+}
+
+/// Exact search used once few empty squares remain: full-width negamax to
+/// the end of the game, scored by the final disc differential.
+fn negamax_exact(board: Board, player: Disc, mut alpha: i32, beta: i32) -> i32 {
+    let opponent = !player;
+    let moves = board.legal_moves(player);
+
+    if moves == 0 {
+        if board.legal_moves(opponent) == 0 {
+            return disc_diff(&board, player);
+        }
+        return -negamax_exact(board, opponent, -beta, -alpha);
+    }
+
+    let mut value = i32::MIN + 1;
+    for pos in iter_bits(moves) {
+        let mut next = board;
+        next.apply_move(pos, player);
+        let score = -negamax_exact(next, opponent, -beta, -alpha);
+        value = value.max(score);
+        alpha = alpha.max(value);
+        if alpha >= beta {
+            break;
+        }
+    }
+    value
+}
+
+/// Heuristic midgame search, handing off to [`negamax_exact`] once few
+/// empty squares remain. `hash` is the Zobrist hash of `board` with `player`
+/// to move, maintained incrementally by the caller; it is used to probe and
+/// fill `tt`.
+#[allow(clippy::too_many_arguments)]
+fn negamax(
+    board: Board,
+    player: Disc,
+    hash: u64,
+    depth: u8,
+    mut alpha: i32,
+    mut beta: i32,
+    tt: &mut TranspositionTable,
+) -> i32 {
+    let empties = 64 - board.occupied().count_ones();
+    if empties <= ENDGAME_EXACT_EMPTIES {
+        return negamax_exact(board, player, alpha, beta);
+    }
+
+    let alpha_orig = alpha;
+    let mut tt_move = None;
+    if let Some((entry_depth, value, bound, best_move)) = tt.probe(hash) {
+        tt_move = best_move;
+        if entry_depth >= depth {
+            match bound {
+                Bound::Exact => return value,
+                Bound::Lower => alpha = alpha.max(value),
+                Bound::Upper => beta = beta.min(value),
+            }
+            if alpha >= beta {
+                return value;
+            }
+        }
+    }
+
+    let opponent = !player;
+    let moves = board.legal_moves(player);
+
+    if moves == 0 {
+        if board.legal_moves(opponent) == 0 {
+            return disc_diff(&board, player);
+        }
+        return -negamax(board, opponent, pass_hash(hash), depth, -beta, -alpha, tt);
+    }
+
+    if depth == 0 {
+        return evaluate(&board, player);
+    }
+
+    let mut value = i32::MIN + 1;
+    let mut best_move = None;
+    for pos in ordered_moves(moves, tt_move) {
+        let mut next = board;
+        let flipped = next.apply_move(pos, player);
+        let next_hash = move_hash(hash, player, pos, flipped);
+
+        let score = -negamax(next, opponent, next_hash, depth - 1, -beta, -alpha, tt);
+        if score > value {
+            value = score;
+            best_move = Some(pos);
+        }
+        alpha = alpha.max(value);
+        if alpha >= beta {
+            break;
+        }
+    }
+
+    let bound = if value <= alpha_orig {
+        Bound::Upper
+    } else if value >= beta {
+        Bound::Lower
+    } else {
+        Bound::Exact
+    };
+    tt.store(hash, depth, value, bound, best_move);
+
+    value
+}
+
+/// The Zobrist hash of `board` with `player` to move, computed from scratch.
+/// See [`move_hash`]/[`pass_hash`] to update an existing hash incrementally
+/// instead, which is what [`Game`] does so this never has to be recomputed
+/// mid-game.
+#[must_use]
+pub(crate) fn hash_of(board: &Board, player: Disc) -> u64 {
+    table::hash_of(board, player)
+}
+
+/// The Zobrist hash after `player` plays at `pos` and captures `flipped`,
+/// derived incrementally from `hash` instead of recomputed from scratch.
+#[must_use]
+pub(crate) fn move_hash(hash: u64, player: Disc, pos: u8, flipped: u64) -> u64 {
+    let mut hash = hash ^ table::square_key(player, pos) ^ table::side_to_move_key();
+    for flipped_pos in iter_bits(flipped) {
+        hash ^= table::flip_key(flipped_pos);
+    }
+    hash
+}
+
+/// The Zobrist hash after a pass, i.e. only the side to move changes.
+#[must_use]
+pub(crate) fn pass_hash(hash: u64) -> u64 {
+    hash ^ table::side_to_move_key()
+}
+
+/// Yields `moves`' set bits, searching `preferred` (typically the best move
+/// found for this position on a previous, shallower search) first.
+fn ordered_moves(moves: u64, preferred: Option<u8>) -> impl Iterator<Item = u8> {
+    let preferred = preferred.filter(|&pos| moves & (1 << pos) != 0);
+    preferred
+        .into_iter()
+        .chain(iter_bits(moves & !preferred.map_or(0, |pos| 1 << pos)))
+}
+
+/// Search the best move for the side to move in `game`, looking `depth`
+/// plies ahead (see [`Difficulty::depth`]). Returns `None` if the side to
+/// move has no legal move.
+///
+/// `tt` is reused across calls instead of being rebuilt every move, so pass
+/// the same table for every move of one game to keep benefiting from
+/// transpositions found on earlier plies.
+///
+/// The returned coordinates are in `(row, col)` order, ready to be passed to
+/// [`Game::make_turn`].
+#[must_use]
+pub fn best_move(game: &Game, depth: u8, tt: &mut TranspositionTable) -> Option<(u8, u8)> {
+    let board = game.board();
+    let player = game.turn();
+
+    let moves = board.legal_moves(player);
+    if moves == 0 {
+        return None;
+    }
+
+    let hash = game.hash();
+
+    let mut best = None;
+    let mut best_score = i32::MIN;
+    for pos in iter_bits(moves) {
+        let mut next = board;
+        let flipped = next.apply_move(pos, player);
+        let next_hash = move_hash(hash, player, pos, flipped);
+
+        let score = -negamax(
+            next,
+            !player,
+            next_hash,
+            depth.saturating_sub(1),
+            i32::MIN + 1,
+            i32::MAX - 1,
+            tt,
+        );
+        if score > best_score {
+            best_score = score;
+            best = Some(pos);
+        }
+    }
+
+    best.map(|pos| (pos / 8, pos % 8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn best_move_on_the_opening_position_is_a_known_legal_move() {
+        let game = Game::new("White", "Black");
+        let mut tt = TranspositionTable::with_capacity(1 << 10);
+        let (row, col) = best_move(&game, 2, &mut tt).expect("black always has an opening move");
+        let played = format!("{}{}", (b'a' + col) as char, row + 1);
+        assert!(["d3", "c4", "f5", "e6"].contains(&played.as_str()));
+    }
+
+    #[test]
+    fn heuristic_search_agrees_with_the_exact_endgame_search() {
+        // 11 empty squares, one above `ENDGAME_EXACT_EMPTIES`: `negamax` does
+        // exactly one ply of its own bookkeeping (TT probe, alpha-beta, pass
+        // handling) before every branch hands off to `negamax_exact`, so a
+        // sign error in either would make the two searches disagree.
+        let board: Board = ("BW".repeat(26) + "B" + &"-".repeat(11)).parse().unwrap();
+        let player = Disc::Black;
+        let hash = hash_of(&board, player);
+
+        let mut tt = TranspositionTable::with_capacity(1 << 10);
+        let heuristic = negamax(board, player, hash, 1, i32::MIN + 1, i32::MAX - 1, &mut tt);
+        let exact = negamax_exact(board, player, i32::MIN + 1, i32::MAX - 1);
+
+        assert_eq!(heuristic, exact);
+    }
+}
@@ -0,0 +1,163 @@
+//! Zobrist hashing and the transposition table the search uses to skip
+//! re-expanding positions it has already seen. [`hash_of`] computes a
+//! position's hash from scratch; [`square_key`], [`flip_key`] and
+//! [`side_to_move_key`] let the caller maintain it incrementally as moves are
+//! made instead.
+
+use std::sync::OnceLock;
+
+use crate::{iter_bits, Board, Disc};
+
+/// A small, dependency-free splitmix64 generator, used only to fill the
+/// Zobrist key tables once at startup.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// One random key per color per square, plus one for the side to move.
+struct ZobristKeys {
+    squares: [[u64; 64]; 2],
+    side_to_move: u64,
+}
+
+static ZOBRIST_KEYS: OnceLock<ZobristKeys> = OnceLock::new();
+
+fn keys() -> &'static ZobristKeys {
+    ZOBRIST_KEYS.get_or_init(|| {
+        let mut rng = SplitMix64(0x4f74_6865_626f_7421); // "Othebot!" as bytes
+        let mut squares = [[0; 64]; 2];
+        for color in &mut squares {
+            for key in color {
+                *key = rng.next();
+            }
+        }
+        ZobristKeys {
+            squares,
+            side_to_move: rng.next(),
+        }
+    })
+}
+
+fn color_idx(color: Disc) -> usize {
+    match color {
+        Disc::Black => 0,
+        Disc::White => 1,
+        Disc::Empty => panic!("The color should not be an empty disc."),
+    }
+}
+
+/// The key for `color` having a disc on `pos` (the bit index, `row*8+col`).
+pub(crate) fn square_key(color: Disc, pos: u8) -> u64 {
+    keys().squares[color_idx(color)][pos as usize]
+}
+
+/// XORing this toggles a square between being a black disc and a white disc,
+/// used to update the hash of a square that got flipped.
+pub(crate) fn flip_key(pos: u8) -> u64 {
+    square_key(Disc::Black, pos) ^ square_key(Disc::White, pos)
+}
+
+/// The key toggled every time the side to move changes.
+pub(crate) fn side_to_move_key() -> u64 {
+    keys().side_to_move
+}
+
+/// Computes the Zobrist hash of `board` with `turn` to move, from scratch.
+pub(crate) fn hash_of(board: &Board, turn: Disc) -> u64 {
+    let (black, white) = board.bitboards_for(Disc::Black);
+
+    let mut hash = 0;
+    for pos in iter_bits(black) {
+        hash ^= square_key(Disc::Black, pos);
+    }
+    for pos in iter_bits(white) {
+        hash ^= square_key(Disc::White, pos);
+    }
+    if turn == Disc::White {
+        hash ^= side_to_move_key();
+    }
+    hash
+}
+
+/// Whether a stored value is the exact score, or only a bound on it because
+/// an alpha-beta cutoff happened before the node was fully searched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Bound {
+    Exact,
+    Lower,
+    Upper,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    key: u64,
+    depth: u8,
+    value: i32,
+    bound: Bound,
+    best_move: Option<u8>,
+}
+
+/// A fixed-size transposition table, indexed by the low bits of the Zobrist
+/// key, using depth-preferred replacement (a shallower entry never evicts a
+/// deeper one).
+///
+/// Reuse the same table across the successive [`super::best_move`] calls of
+/// one game instead of rebuilding it every move, so entries from earlier
+/// plies can still be hit later in the game.
+pub struct TranspositionTable {
+    entries: Vec<Option<Entry>>,
+    mask: usize,
+}
+
+impl TranspositionTable {
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.next_power_of_two();
+        Self {
+            entries: vec![None; capacity],
+            mask: capacity - 1,
+        }
+    }
+
+    fn slot(&self, key: u64) -> usize {
+        key as usize & self.mask
+    }
+
+    pub(crate) fn probe(&self, key: u64) -> Option<(u8, i32, Bound, Option<u8>)> {
+        self.entries[self.slot(key)]
+            .filter(|entry| entry.key == key)
+            .map(|entry| (entry.depth, entry.value, entry.bound, entry.best_move))
+    }
+
+    pub(crate) fn store(
+        &mut self,
+        key: u64,
+        depth: u8,
+        value: i32,
+        bound: Bound,
+        best_move: Option<u8>,
+    ) {
+        let slot = self.slot(key);
+        let should_replace = match &self.entries[slot] {
+            Some(existing) => existing.depth <= depth,
+            None => true,
+        };
+        if should_replace {
+            self.entries[slot] = Some(Entry {
+                key,
+                depth,
+                value,
+                bound,
+                best_move,
+            });
+        }
+    }
+}
@@ -1,9 +1,16 @@
 use std::{
+    cmp::Ordering,
     error::Error,
     fmt::{self, Display},
+    io::{self, IsTerminal, Write},
     ops::Not,
+    str::FromStr,
+    thread,
+    time::Duration,
 };
 
+pub mod engine;
+
 pub const VERSION_AND_GIT_HASH: &str = env!("VERSION_AND_GIT_HASH");
 
 pub const LICENSE: &str = include_str!("../LICENSE");
@@ -12,6 +19,12 @@ pub const LICENSE: &str = include_str!("../LICENSE");
 pub enum OthebotError {
     IllegalMove,
     LegalMovesNotComputed,
+    /// The player to move has no legal move and must pass their turn
+    /// instead, see [`Game::has_legal_moves`].
+    MustPass,
+    /// A position string didn't match the expected format, see
+    /// [`Board::from_str`] and [`Game::from_str`].
+    InvalidPosition,
 }
 
 impl Error for OthebotError {}
@@ -20,7 +33,9 @@ impl Display for OthebotError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             OthebotError::IllegalMove => write!(f, "illegal move, you can't put your disc here"),
-            OthebotError::LegalMovesNotComputed => write!(f, "INTERNAL ERROR: legal moves were not computed before calling a function that depends on legal moves.")
+            OthebotError::LegalMovesNotComputed => write!(f, "INTERNAL ERROR: legal moves were not computed before calling a function that depends on legal moves."),
+            OthebotError::MustPass => write!(f, "you have no legal move, you must pass your turn"),
+            OthebotError::InvalidPosition => write!(f, "invalid position string"),
         }
     }
 }
@@ -56,27 +71,95 @@ impl Display for Disc {
     }
 }
 
+/// One of the 8 directions a ray can travel on the board, encoded as a shift
+/// amount (applied to a bitboard, negative meaning "shift right") together
+/// with the mask that must be applied *before* shifting so that bits on the
+/// edge of the board don't wrap around onto the next/previous row.
+pub(crate) struct Direction {
+    shift: i8,
+    mask: u64,
+}
+
+/// Every square except those on the `a`-file (`col == 0`).
+const NOT_A_FILE: u64 = 0xfefe_fefe_fefe_fefe;
+/// Every square except those on the `h`-file (`col == 7`).
+const NOT_H_FILE: u64 = 0x7f7f_7f7f_7f7f_7f7f;
+
+pub(crate) const DIRECTIONS: [Direction; 8] = [
+    Direction { shift: 1, mask: NOT_H_FILE },  // East
+    Direction { shift: -1, mask: NOT_A_FILE }, // West
+    Direction { shift: 8, mask: !0 },          // South
+    Direction { shift: -8, mask: !0 },         // North
+    Direction { shift: 9, mask: NOT_H_FILE },  // South-East
+    Direction { shift: -9, mask: NOT_A_FILE }, // North-West
+    Direction { shift: 7, mask: NOT_A_FILE },  // South-West
+    Direction { shift: -7, mask: NOT_H_FILE }, // North-East
+];
+
+/// Mask `bb` to drop the bits that would wrap around the board if shifted,
+/// then shift it in the given [`Direction`].
+#[inline]
+pub(crate) fn shift_dir(bb: u64, dir: &Direction) -> u64 {
+    let masked = bb & dir.mask;
+    if dir.shift >= 0 {
+        masked << dir.shift
+    } else {
+        masked >> -dir.shift
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
 pub struct Board {
-    discs: [Disc; 64],
+    /// Bitboard of the squares occupied by a black disc, bit `i` is square
+    /// `row * 8 + col`.
+    black: u64,
+    /// Bitboard of the squares occupied by a white disc, same indexing as
+    /// [`Board::black`].
+    white: u64,
 }
 
 impl Board {
     /// Create a new board with the starting layout
     pub const fn new() -> Board {
-        use Disc::Black as B;
-        use Disc::Empty as E;
-        use Disc::White as W;
         Board {
-            discs: [
-                E, E, E, E, E, E, E, E, // This
-                E, E, E, E, E, E, E, E, // is
-                E, E, E, E, E, E, E, E, // to
-                E, E, E, W, B, E, E, E, // trick
-                E, E, E, B, W, E, E, E, // the
-                E, E, E, E, E, E, E, E, // rust
-                E, E, E, E, E, E, E, E, // formater
-                E, E, E, E, E, E, E, E, // ;)
-            ],
+            // d5 and e4
+            black: (1 << 35) | (1 << 28),
+            // d4 and e5
+            white: (1 << 27) | (1 << 36),
+        }
+    }
+
+    /// Bitboard of every occupied square, regardless of color.
+    #[inline]
+    pub(crate) fn occupied(&self) -> u64 {
+        self.black | self.white
+    }
+
+    /// Returns `(mine, theirs)`, the bitboards of `player` and of their
+    /// opponent, in that order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `player` is [`Disc::Empty`].
+    #[inline]
+    pub(crate) fn bitboards_for(&self, player: Disc) -> (u64, u64) {
+        match player {
+            Disc::Black => (self.black, self.white),
+            Disc::White => (self.white, self.black),
+            Disc::Empty => panic!("The player should not be an empty disc."),
+        }
+    }
+
+    /// Get the disc present at the bit index `idx` (`row * 8 + col`).
+    #[inline]
+    fn disc_at_idx(&self, idx: u64) -> Disc {
+        let mask = 1 << idx;
+        if self.black & mask != 0 {
+            Disc::Black
+        } else if self.white & mask != 0 {
+            Disc::White
+        } else {
+            Disc::Empty
         }
     }
 
@@ -102,101 +185,105 @@ impl Board {
     #[inline]
     #[must_use]
     pub unsafe fn get_disc_unchecked(&self, col: u8, row: u8) -> Disc {
-        self.discs[(row * 8 + col) as usize]
+        self.disc_at_idx((row * 8 + col) as u64)
     }
 
-    /// Change the disc at those coordinates, don't check if this move is legal.
+    /// Returns the bitmask of discs captured if `player` were to play at
+    /// `pos` (the bit index, `row * 8 + col`), without applying the move.
+    ///
+    /// Walks each of the 8 directions from `pos`, accumulating opposing
+    /// discs; a ray only contributes to the result if it terminates on one of
+    /// `player`'s own discs, per the standard Othello capture rule.
+    #[must_use]
     #[track_caller]
-    fn change_disc(&mut self, (col, row): (u8, u8), disc: Disc) {
-        assert!(col < 8);
-        assert!(row < 8);
-        // UNSAFE: we checked that they are in bounds
-        let idx = (row * 8 + col) as usize;
-        *self.discs.get_mut(idx).unwrap() = disc;
+    pub fn flip(&self, pos: u8, player: Disc) -> u64 {
+        let (mine, theirs) = self.bitboards_for(player);
+
+        let mut captured = 0;
+        for dir in &DIRECTIONS {
+            let mut ray = 0;
+            let mut cursor = shift_dir(1 << pos, dir);
+            while cursor & theirs != 0 {
+                ray |= cursor;
+                cursor = shift_dir(cursor, dir);
+            }
+            if cursor & mine != 0 {
+                captured |= ray;
+            }
+        }
+        captured
+    }
+
+    /// Play `player` at `pos` (the bit index, `row * 8 + col`), flipping the
+    /// captured discs. Does not check that the move is legal, that's the
+    /// caller's responsability. Returns the bitmask of the discs that were
+    /// flipped.
+    #[track_caller]
+    pub(crate) fn apply_move(&mut self, pos: u8, player: Disc) -> u64 {
+        let flipped = self.flip(pos, player);
+        let placed = 1 << pos;
+        match player {
+            Disc::Black => {
+                self.black ^= flipped | placed;
+                self.white ^= flipped;
+            }
+            Disc::White => {
+                self.white ^= flipped | placed;
+                self.black ^= flipped;
+            }
+            Disc::Empty => panic!("The player should not be an empty disc."),
+        }
+        flipped
+    }
+
+    /// Set the disc at the bit index `pos` (`row * 8 + col`) to `color`,
+    /// without flipping anything. Used to reveal a move one disc at a time,
+    /// see [`Game::animate`].
+    pub(crate) fn set_disc(&mut self, pos: u8, color: Disc) {
+        let mask = 1 << pos;
+        match color {
+            Disc::Black => {
+                self.black |= mask;
+                self.white &= !mask;
+            }
+            Disc::White => {
+                self.white |= mask;
+                self.black &= !mask;
+            }
+            Disc::Empty => panic!("The color should not be an empty disc."),
+        }
     }
 
     /// Returns the scores of the current board, in the tuple, white's score is
     /// first, and black's score is second
     pub fn scores(&self) -> (u8, u8) {
-        let mut white = 0;
-        let mut black = 0;
-        for disc in self.discs {
-            match disc {
-                Disc::White => white += 1,
-                Disc::Black => black += 1,
-                Disc::Empty => {}
-            }
-        }
-        (white, black)
+        (self.white.count_ones() as u8, self.black.count_ones() as u8)
     }
 
     /// Return the current legal moves for the `player` into a bitfield format.
     ///
     /// The first bit of the bitfield is the first disc at index 0 and the last
     /// bit is index 63.
+    ///
+    /// Uses the branchless dumb7fill (a.k.a. Kogge-Stone) generator: from
+    /// `player`'s discs, each direction's run is extended one step at a time
+    /// through consecutive opponent discs, and a move is legal wherever such
+    /// a run lands on an empty square.
     #[must_use]
     #[track_caller]
     pub fn legal_moves(&self, player: Disc) -> u64 {
-        let mut bitfield = 0;
-
-        if player == Disc::Empty {
-            panic!("The player should not be an empty disc.")
-        }
+        let (mine, theirs) = self.bitboards_for(player);
+        let empty = !(mine | theirs);
 
-        let directions: [(i32, i32); 8] = [
-            (-1, -1), // RIGHT UP
-            (0, -1),  // UP
-            (1, -1),  // LEFT-UP
-            (-1, 0),  // RIGHT
-            (1, 0),   // LEFT
-            (-1, 1),  // LEFT-DOWN
-            (0, -1),  // DOWN
-            (1, 1),   // RIGHT-DOWN
-        ];
-
-        for y in 0..8 {
-            for x in 0..8 {
-                let idx = y * 8 + x;
-
-                // The disc is already filed
-                if self.discs[idx] != Disc::Empty {
-                    continue;
-                }
+        let mut bitfield = 0;
+        for dir in &DIRECTIONS {
+            let theirs = theirs & dir.mask;
 
-                for (dx, dy) in directions {
-                    // coordinates of next disc in direction
-                    let mut nx = x as i32 + dx;
-                    let mut ny = y as i32 + dy;
-
-                    // whetever a disc of the other color was present in the
-                    // line of the direction
-                    let mut captured = false;
-
-                    while nx >= 0 && nx < 8 && ny >= 0 && ny < 8 {
-                        let n_idx = (ny * 8 + nx) as usize;
-
-                        if self.discs[n_idx] == Disc::Empty {
-                            break;
-                        }
-
-                        if self.discs[n_idx] == player {
-                            if captured {
-                                // we already encountered an opposite disc, we
-                                // know it is a good move
-                                bitfield |= 1 << idx;
-                            }
-                            break;
-                        }
-                        // we encountered an opposite disc, so if later we
-                        // encounter in the same direction a disc of player's
-                        // color, it's a valid move
-                        captured = true;
-                        // update the coordinates to continue in this direction
-                        nx += dx;
-                        ny += dy;
-                    }
-                }
+            let mut run = shift_dir(mine, dir) & theirs;
+            for _ in 0..6 {
+                run |= shift_dir(run, dir) & theirs;
             }
+            bitfield |= shift_dir(run, dir) & empty;
         }
 
         bitfield
@@ -210,6 +297,47 @@ impl Default for Board {
     }
 }
 
+/// Parses a board from 64 characters, one per square in row-major order
+/// (`row * 8 + col`), `B` for a black disc, `W` for a white disc and `-` for
+/// an empty square.
+impl FromStr for Board {
+    type Err = OthebotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.chars().count() != 64 {
+            return Err(OthebotError::InvalidPosition);
+        }
+
+        let mut black = 0;
+        let mut white = 0;
+        for (idx, c) in s.chars().enumerate() {
+            match c {
+                'B' => black |= 1 << idx,
+                'W' => white |= 1 << idx,
+                '-' => {}
+                _ => return Err(OthebotError::InvalidPosition),
+            }
+        }
+
+        Ok(Board { black, white })
+    }
+}
+
+/// Emits the same format parsed by [`Board::from_str`].
+impl Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for idx in 0..64 {
+            let c = match self.disc_at_idx(idx) {
+                Disc::Black => 'B',
+                Disc::White => 'W',
+                Disc::Empty => '-',
+            };
+            write!(f, "{c}")?;
+        }
+        Ok(())
+    }
+}
+
 /// Converts an algebric notation like `a1`, `g8`, `b7` etc to `(0, 0)`,
 /// `(6, 7)`, `(1, 6)`.
 pub fn algebric2xy(pos: &str) -> Result<(u8, u8), OthebotError> {
@@ -231,6 +359,82 @@ pub fn algebric2xy(pos: &str) -> Result<(u8, u8), OthebotError> {
     Ok((col - b'a', row - b'1'))
 }
 
+/// Iterate over the bit indices that are set in `bitfield`.
+pub(crate) fn iter_bits(mut bitfield: u64) -> impl Iterator<Item = u8> {
+    std::iter::from_fn(move || {
+        if bitfield == 0 {
+            return None;
+        }
+        let pos = bitfield.trailing_zeros() as u8;
+        bitfield &= bitfield - 1;
+        Some(pos)
+    })
+}
+
+/// One of the roles a rendered glyph can play, used to pick its ANSI color.
+enum AnsiColor {
+    Black,
+    White,
+    LegalMove,
+}
+
+impl AnsiColor {
+    fn code(&self) -> &'static str {
+        match self {
+            // discs themselves are rendered in bold so they stay legible
+            // regardless of the terminal's default foreground color.
+            AnsiColor::Black => "\x1b[1;34m",
+            AnsiColor::White => "\x1b[1;33m",
+            AnsiColor::LegalMove => "\x1b[2m",
+        }
+    }
+}
+
+/// Styling knobs for [`Game::render_to`] and [`Game::animate`]: whether to
+/// colorize discs, highlight legal moves, print coordinate labels, and which
+/// glyph to use for each kind of square.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayOptions {
+    /// Whether to colorize the discs using ANSI escape codes.
+    pub colors: bool,
+    /// Whether to mark empty squares that are legal moves.
+    pub highlight_legal_moves: bool,
+    /// Whether to print the `a`-`h` column labels below the board.
+    pub coordinates: bool,
+    /// Glyph used for a black disc.
+    pub black_glyph: char,
+    /// Glyph used for a white disc.
+    pub white_glyph: char,
+    /// Glyph used for an empty square that's a legal move.
+    pub legal_move_glyph: char,
+    /// Glyph used for an empty square that isn't a legal move.
+    pub empty_glyph: char,
+}
+
+impl DisplayOptions {
+    fn write_glyph(&self, w: &mut impl Write, glyph: char, color: AnsiColor) -> io::Result<()> {
+        if self.colors {
+            write!(w, "{}{glyph}\x1b[0m", color.code())
+        } else {
+            write!(w, "{glyph}")
+        }
+    }
+}
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        DisplayOptions {
+            colors: true,
+            highlight_legal_moves: true,
+            coordinates: true,
+            black_glyph: 'B',
+            white_glyph: 'W',
+            legal_move_glyph: '•',
+            empty_glyph: ' ',
+        }
+    }
+}
+
 pub struct Game {
     board: Board,
 
@@ -249,15 +453,22 @@ pub struct Game {
     turn: Disc,
     /// The legal moves of the current player (`turn` field).
     current_legal_moves: Option<u64>,
+    /// The Zobrist hash of `board` with `turn` to move, maintained
+    /// incrementally (see [`engine::move_hash`]/[`engine::pass_hash`]) so
+    /// [`engine::best_move`] never has to rehash the position from scratch.
+    hash: u64,
 }
 
 impl Game {
     pub fn new(white_player: impl Into<String>, black_player: impl Into<String>) -> Game {
+        let board = Board::new();
+        let turn = Disc::Black;
         Game {
-            board: Board::new(),
+            hash: engine::hash_of(&board, turn),
+            board,
             white_player: white_player.into(),
             black_player: black_player.into(),
-            turn: Disc::Black,
+            turn,
             current_legal_moves: None,
         }
     }
@@ -266,23 +477,63 @@ impl Game {
         self.turn
     }
 
-    pub fn make_turn(&mut self, mov @ (row, col): (u8, u8)) -> Result<(), OthebotError> {
+    /// A snapshot of the current board.
+    #[inline]
+    pub(crate) fn board(&self) -> Board {
+        self.board
+    }
+
+    /// The Zobrist hash of [`Game::board`] with [`Game::turn`] to move.
+    #[inline]
+    pub(crate) fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    pub fn make_turn(&mut self, (row, col): (u8, u8)) -> Result<(), OthebotError> {
         // ensure the move is inside the legal moves.
         let idx = (row * 8 + col) as u64;
         let Some(legal_moves) = self.current_legal_moves else {
             return Err(OthebotError::LegalMovesNotComputed);
         };
+        if legal_moves == 0 {
+            return Err(OthebotError::MustPass);
+        }
         let mov_bitfield = 1 << idx;
         if legal_moves & mov_bitfield == 0 {
             return Err(OthebotError::IllegalMove);
         }
-        self.board.change_disc(mov, self.turn);
+        let flipped = self.board.apply_move(idx as u8, self.turn);
+        self.hash = engine::move_hash(self.hash, self.turn, idx as u8, flipped);
         self.turn = !self.turn;
 
         self.current_legal_moves = None;
         Ok(())
     }
 
+    /// Whether the player to move (`turn`) has at least one legal move.
+    #[must_use]
+    pub fn has_legal_moves(&self) -> bool {
+        self.board.legal_moves(self.turn) != 0
+    }
+
+    /// Whether the game has ended, i.e. neither color has a legal move.
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        self.board.legal_moves(Disc::Black) == 0 && self.board.legal_moves(Disc::White) == 0
+    }
+
+    /// The winner of the game, by score, or `None` if it's a tie. Only
+    /// meaningful once [`Game::is_over`] returns `true`.
+    #[must_use]
+    pub fn winner(&self) -> Option<Disc> {
+        let (white, black) = self.board.scores();
+        match white.cmp(&black) {
+            Ordering::Greater => Some(Disc::White),
+            Ordering::Less => Some(Disc::Black),
+            Ordering::Equal => None,
+        }
+    }
+
     #[inline]
     #[must_use]
     pub fn white_name(&self) -> &str {
@@ -305,61 +556,370 @@ impl Game {
         }
     }
 
-    /// Renders the board game to stdout
+    /// Renders the board game to stdout, with the default [`DisplayOptions`]
+    /// except `colors`, which is only turned on when stdout is a terminal (so
+    /// piping or redirecting the output doesn't fill it with raw escapes).
     pub fn render(&self) -> Result<(), OthebotError> {
-        // TODO: Add colors.
+        let opts = DisplayOptions {
+            colors: io::stdout().is_terminal(),
+            ..DisplayOptions::default()
+        };
+        self.render_to(&mut io::stdout(), &opts)
+    }
+
+    /// Renders the board game to `w`, styled according to `opts`.
+    pub fn render_to(&self, w: &mut impl Write, opts: &DisplayOptions) -> Result<(), OthebotError> {
         let Some(legal_moves) = self.current_legal_moves else {
             return Err(OthebotError::LegalMovesNotComputed);
         };
 
+        self.write_board(w, legal_moves, opts)
+            .expect("failed to write the rendered board");
+        Ok(())
+    }
+
+    fn write_board(
+        &self,
+        w: &mut impl Write,
+        legal_moves: u64,
+        opts: &DisplayOptions,
+    ) -> io::Result<()> {
         for row in 0..8 {
-            print!("+---+---+---+---+---+---+---+---+");
+            write!(w, "+---+---+---+---+---+---+---+---+")?;
 
             // print the scores
             if row == 7 {
                 let (white_score, black_score) = self.board.scores();
-                print!(
+                write!(
+                    w,
                     "    {}: {}  {}: {}",
                     self.black_name(),
                     black_score,
                     self.white_name(),
                     white_score,
-                );
+                )?;
             }
 
-            println!();
+            writeln!(w)?;
 
             for col in 0..8 {
                 let idx = row * 8 + col;
-                let is_legal_move = (1 << idx) & legal_moves != 0;
-                let disc = self.board.discs[idx];
-                print!("| ");
+                let is_legal_move = opts.highlight_legal_moves && (1 << idx) & legal_moves != 0;
+                let disc = self.board.get_disc((col as u8, row as u8));
+                write!(w, "| ")?;
                 match disc {
-                    Disc::White => print!("W"),
-                    Disc::Black => print!("B"),
-                    Disc::Empty if is_legal_move => print!("•"),
-                    Disc::Empty => print!(" "),
+                    Disc::White => opts.write_glyph(w, opts.white_glyph, AnsiColor::White)?,
+                    Disc::Black => opts.write_glyph(w, opts.black_glyph, AnsiColor::Black)?,
+                    Disc::Empty if is_legal_move => {
+                        opts.write_glyph(w, opts.legal_move_glyph, AnsiColor::LegalMove)?
+                    }
+                    Disc::Empty => write!(w, "{}", opts.empty_glyph)?,
                 }
-                print!(" ");
+                write!(w, " ")?;
             }
 
-            print!("| {}", row + 1);
+            write!(w, "| {}", row + 1)?;
 
             // print the score
             if row == 6 {
-                print!("  SCORES:");
+                write!(w, "  SCORES:")?;
             }
 
-            println!();
+            writeln!(w)?;
+        }
+        writeln!(w, "+---+---+---+---+---+---+---+---+")?;
+        if opts.coordinates {
+            writeln!(w, "  a   b   c   d   e   f   g   h")?;
         }
-        println!("+---+---+---+---+---+---+---+---+");
-        println!("  a   b   c   d   e   f   g   h");
 
         Ok(())
     }
 
-    /// Compute and store the legal moves of the current player.
+    /// Plays `pos`, like [`Game::make_turn`], but redraws the board to `w`
+    /// once after placing the disc and then once per captured disc so a
+    /// human watching along can follow the capture, waiting `frame_delay`
+    /// after each frame.
+    pub fn animate(
+        &mut self,
+        w: &mut impl Write,
+        pos: (u8, u8),
+        opts: &DisplayOptions,
+        frame_delay: Duration,
+    ) -> Result<(), OthebotError> {
+        let (row, col) = pos;
+        let idx = (row * 8 + col) as u64;
+        let Some(legal_moves) = self.current_legal_moves else {
+            return Err(OthebotError::LegalMovesNotComputed);
+        };
+        if legal_moves == 0 {
+            return Err(OthebotError::MustPass);
+        }
+        if legal_moves & (1 << idx) == 0 {
+            return Err(OthebotError::IllegalMove);
+        }
+
+        let player = self.turn;
+        let flip_mask = self.board.flip(idx as u8, player);
+
+        self.board.set_disc(idx as u8, player);
+        self.redraw_frame(w, opts);
+        thread::sleep(frame_delay);
+
+        for flipped_pos in iter_bits(flip_mask) {
+            self.board.set_disc(flipped_pos, player);
+            self.redraw_frame(w, opts);
+            thread::sleep(frame_delay);
+        }
+
+        self.hash = engine::move_hash(self.hash, player, idx as u8, flip_mask);
+        self.turn = !self.turn;
+        self.current_legal_moves = None;
+        Ok(())
+    }
+
+    /// Clears the screen (if `opts.colors`, since it assumes an ANSI
+    /// terminal) then draws the board to `w`, for [`Game::animate`].
+    fn redraw_frame(&self, w: &mut impl Write, opts: &DisplayOptions) {
+        if opts.colors {
+            write!(w, "\x1b[2J\x1b[H").expect("failed to write to `w`");
+        }
+        // legal moves are irrelevant while a capture is animating.
+        self.write_board(w, 0, opts)
+            .expect("failed to write the rendered board");
+    }
+
+    /// Compute and store the legal moves of the current player, automatically
+    /// passing turns (flipping `turn` without placing a disc) while the
+    /// player to move has none, until either a move is available or the game
+    /// is over (in which case `current_legal_moves` is stored as `Some(0)`).
     pub fn legal_moves(&mut self) {
-        self.current_legal_moves = Some(self.board.legal_moves(self.turn()));
+        loop {
+            let moves = self.board.legal_moves(self.turn);
+            if moves != 0 || self.board.legal_moves(!self.turn) == 0 {
+                self.current_legal_moves = Some(moves);
+                return;
+            }
+            // `turn` has no legal move but the opponent does, pass.
+            self.hash = engine::pass_hash(self.hash);
+            self.turn = !self.turn;
+        }
+    }
+}
+
+/// Parses a game from a [`Board`] position string (see [`Board::from_str`]),
+/// immediately followed by a turn marker (`B` or `W`), a `;`, the white
+/// player's name, another `;`, and the black player's name.
+impl FromStr for Game {
+    type Err = OthebotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((boundary, _)) = s.char_indices().nth(64) else {
+            return Err(OthebotError::InvalidPosition);
+        };
+        let (position, rest) = s.split_at(boundary);
+        let board: Board = position.parse()?;
+
+        let mut rest = rest.chars();
+        let turn = match rest.next() {
+            Some('B') => Disc::Black,
+            Some('W') => Disc::White,
+            _ => return Err(OthebotError::InvalidPosition),
+        };
+
+        let names = rest
+            .as_str()
+            .strip_prefix(';')
+            .ok_or(OthebotError::InvalidPosition)?;
+        let Some((white_player, black_player)) = names.split_once(';') else {
+            return Err(OthebotError::InvalidPosition);
+        };
+
+        Ok(Game {
+            hash: engine::hash_of(&board, turn),
+            board,
+            white_player: white_player.to_string(),
+            black_player: black_player.to_string(),
+            turn,
+            current_legal_moves: None,
+        })
+    }
+}
+
+/// Emits the same format parsed by [`Game::from_str`].
+impl Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let turn = match self.turn {
+            Disc::Black => 'B',
+            Disc::White => 'W',
+            Disc::Empty => unreachable!(),
+        };
+        write!(
+            f,
+            "{}{turn};{};{}",
+            self.board, self.white_player, self.black_player
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The bit index of `square` (e.g. `"d3"`), via [`algebric2xy`].
+    fn sq(square: &str) -> u8 {
+        let (col, row) = algebric2xy(square).unwrap();
+        row * 8 + col
+    }
+
+    /// The bitmask with one bit set per square in `squares`.
+    fn bits(squares: &[&str]) -> u64 {
+        squares.iter().fold(0, |acc, s| acc | (1 << sq(s)))
+    }
+
+    #[test]
+    fn opening_flip() {
+        let board = Board::new();
+        // Each of black's four opening moves captures the single white disc
+        // it faces towards the center.
+        assert_eq!(board.flip(sq("d3"), Disc::Black), bits(&["d4"]));
+        assert_eq!(board.flip(sq("c4"), Disc::Black), bits(&["d4"]));
+        assert_eq!(board.flip(sq("f5"), Disc::Black), bits(&["e5"]));
+        assert_eq!(board.flip(sq("e6"), Disc::Black), bits(&["e5"]));
+    }
+
+    #[test]
+    fn flip_captures_in_several_directions_from_the_move() {
+        // Black to play at e4, with a two-disc white run to its east and
+        // another to its south, each closed off by a black disc.
+        let board: Board = "-----------------------------WWB----W-------W-------B-----------"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            board.flip(sq("e4"), Disc::Black),
+            bits(&["f4", "g4", "e5", "e6"])
+        );
+    }
+
+    #[test]
+    fn opening_legal_moves() {
+        let board = Board::new();
+        assert_eq!(
+            board.legal_moves(Disc::Black),
+            bits(&["d3", "c4", "f5", "e6"])
+        );
+        assert_eq!(
+            board.legal_moves(Disc::White),
+            bits(&["e3", "f4", "c5", "d6"])
+        );
+    }
+
+    #[test]
+    fn legal_moves_on_a_midgame_position() {
+        // Same position as `flip_captures_in_several_directions_from_the_move`:
+        // black has a single legal move, white has a single legal move.
+        let board: Board = "-----------------------------WWB----W-------W-------B-----------"
+            .parse()
+            .unwrap();
+        assert_eq!(board.legal_moves(Disc::Black), bits(&["e4"]));
+        assert_eq!(board.legal_moves(Disc::White), bits(&["e8"]));
+    }
+
+    #[test]
+    fn legal_moves_auto_passes_when_the_side_to_move_is_stuck() {
+        // Black has no legal move here, White has one at d1.
+        let position = "BWB".to_string() + &"-".repeat(61) + "B;White;Black";
+        let mut game: Game = position.parse().unwrap();
+        assert!(!game.has_legal_moves());
+
+        game.legal_moves();
+        assert_eq!(game.turn(), Disc::White);
+        assert!(game.has_legal_moves());
+        assert!(!game.is_over());
+    }
+
+    #[test]
+    fn is_over_and_winner_on_a_full_board() {
+        // A filled board has no empty square for either side to play on,
+        // regardless of who's disc is where.
+        let tie = "BW".repeat(32) + "B;White;Black";
+        let mut game: Game = tie.parse().unwrap();
+        game.legal_moves();
+        assert!(game.is_over());
+        assert_eq!(game.winner(), None);
+        assert!(matches!(game.make_turn((0, 0)), Err(OthebotError::MustPass)));
+
+        let black_wins = "B".repeat(64) + "B;White;Black";
+        let mut game: Game = black_wins.parse().unwrap();
+        game.legal_moves();
+        assert!(game.is_over());
+        assert_eq!(game.winner(), Some(Disc::Black));
+    }
+
+    #[test]
+    fn board_round_trips_through_its_string_format() {
+        let s = "---------------------------WB------BW---------------------------";
+        assert_eq!(s.parse::<Board>().unwrap().to_string(), s);
+    }
+
+    #[test]
+    fn game_round_trips_through_its_string_format() {
+        let s = "---------------------------WB------BW---------------------------\
+                 B;Othebot;You";
+        assert_eq!(s.parse::<Game>().unwrap().to_string(), s);
+    }
+
+    #[test]
+    fn malformed_position_strings_are_rejected() {
+        // Too short to be 64 squares.
+        assert!(matches!(
+            "---".parse::<Board>(),
+            Err(OthebotError::InvalidPosition)
+        ));
+
+        // Invalid turn marker.
+        let bad_turn = "-".repeat(64) + "X;White;Black";
+        assert!(matches!(
+            bad_turn.parse::<Game>(),
+            Err(OthebotError::InvalidPosition)
+        ));
+
+        // Missing the `;` separating the turn marker from the player names.
+        let missing_semicolon = "-".repeat(64) + "BWhite;Black";
+        assert!(matches!(
+            missing_semicolon.parse::<Game>(),
+            Err(OthebotError::InvalidPosition)
+        ));
+    }
+
+    #[test]
+    fn render_to_respects_custom_display_options() {
+        let mut game = Game::new("Othebot", "You");
+        game.legal_moves();
+
+        let opts = DisplayOptions {
+            colors: false,
+            highlight_legal_moves: true,
+            coordinates: true,
+            black_glyph: 'X',
+            white_glyph: 'O',
+            legal_move_glyph: '*',
+            empty_glyph: ' ',
+        };
+
+        let mut out = Vec::new();
+        game.render_to(&mut out, &opts).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        // no ANSI escapes since `colors` is off
+        assert!(!out.contains('\u{1b}'));
+        // the opening position's discs, in the custom glyphs
+        assert!(out.contains("| O | X |"));
+        assert!(out.contains("| X | O |"));
+        // one of black's opening legal moves, highlighted with the custom glyph
+        assert!(out.contains("| * |"));
+        // the coordinate row, since `coordinates` is on
+        assert!(out.contains("  a   b   c   d   e   f   g   h"));
+        // the score line, using the names passed to `Game::new`
+        assert!(out.contains("You: 2  Othebot: 2"));
     }
 }